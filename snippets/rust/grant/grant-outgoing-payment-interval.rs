@@ -0,0 +1,92 @@
+//@! start chunk 1 | title=Import dependencies
+use open_payments::client::api::UnauthenticatedResources;
+use open_payments::client::AuthenticatedResources;
+use open_payments::snippets::utils::{create_authenticated_client, get_env_var, load_env};
+use open_payments::types::{
+    auth::{
+        AccessItem, AccessTokenRequest, GrantRequest, InteractFinish, InteractRequest,
+        LimitsOutgoing, OutgoingPaymentAction,
+    },
+    GrantResponse,
+};
+use uuid::Uuid;
+//@! end chunk 1
+
+#[tokio::main]
+async fn main() -> open_payments::client::Result<()> {
+    load_env()?;
+
+    //@! start chunk 2 | title=Initialize Open Payments client
+    // Authenticated client can be also used for unauthenticated resources
+    let client = create_authenticated_client()?;
+    //@! end chunk 2
+
+    //@! start chunk 3 | title=Get wallet address information
+    let wallet_address_url = get_env_var("WALLET_ADDRESS_URL")?;
+    let wallet_address = client.wallet_address().get(&wallet_address_url).await?;
+    //@! end chunk 3
+
+    let quote_url = get_env_var("QUOTE_URL")?;
+    let access_token = get_env_var("QUOTE_ACCESS_TOKEN")?;
+    let quote = client.quotes().get(&quote_url, Some(&access_token)).await?;
+
+    //@! start chunk 4 | title=Request outgoing payment grant with interval
+    let wallet_id = &wallet_address.id;
+    let grant_request = GrantRequest::new(
+        AccessTokenRequest {
+            access: vec![AccessItem::OutgoingPayment {
+                actions: vec![
+                    OutgoingPaymentAction::List,
+                    OutgoingPaymentAction::ListAll,
+                    OutgoingPaymentAction::Read,
+                    OutgoingPaymentAction::ReadAll,
+                    OutgoingPaymentAction::Create,
+                ],
+                identifier: wallet_id.to_string(),
+                limits: Some(LimitsOutgoing {
+                    receiver: None,
+                    debit_amount: Some(quote.debit_amount),
+                    receive_amount: None,
+                    interval: Some("R/2016-08-24T08:00:00Z/P1D".to_string()),
+                }),
+            }],
+        },
+        Some(InteractRequest {
+            start: vec!["redirect".to_string()],
+            finish: Some(InteractFinish {
+                method: "redirect".to_string(),
+                uri: "http://localhost:3344".to_string(),
+                nonce: Uuid::new_v4().to_string(),
+            }),
+        }),
+    );
+
+    let response = client
+        .grant()
+        .request(&wallet_address.auth_server, &grant_request)
+        .await?;
+    //@! end chunk 4
+
+    //@! start chunk 5 | title=Check grant state
+    let GrantResponse::WithInteraction {
+        interact,
+        continue_,
+    } = response
+    else {
+        return Err(open_payments::client::OpClientError::other(
+            "Expected pending/interactive grant".to_string(),
+        ));
+    };
+    //@! end chunk 5
+
+    //@! start chunk 6 | title=Output
+    println!(
+        "Please interact at the following URL: {}",
+        interact.redirect
+    );
+    println!("CONTINUE_ACCESS_TOKEN = {}", continue_.access_token.value);
+    println!("CONTINUE_URI = {}", continue_.uri);
+    //@! end chunk 6
+
+    Ok(())
+}